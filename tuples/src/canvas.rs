@@ -0,0 +1,169 @@
+use crate::Tuple;
+
+/// The maximum line length allowed in the PPM (P3) output, per the format spec.
+const MAX_LINE_LENGTH: usize = 70;
+
+/// A grid of pixels, each a color `Tuple` (`x = r`, `y = g`, `z = b`).
+pub struct Canvas {
+    width: usize,
+    height: usize,
+    pixels: Vec<Tuple>,
+}
+
+impl Canvas {
+    pub fn new(width: usize, height: usize) -> Self {
+        Canvas {
+            width,
+            height,
+            pixels: vec![Tuple::new(0.0, 0.0, 0.0, 0.0); width * height],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn write_pixel(&mut self, x: usize, y: usize, color: Tuple) {
+        self.pixels[y * self.width + x] = color;
+    }
+
+    pub fn pixel_at(&self, x: usize, y: usize) -> Tuple {
+        self.pixels[y * self.width + x]
+    }
+
+    pub fn to_ppm(&self) -> String {
+        let mut ppm = format!("P3\n{} {}\n255\n", self.width, self.height);
+
+        for row in self.pixels.chunks(self.width) {
+            let components: Vec<String> = row
+                .iter()
+                .flat_map(|color| [color.x, color.y, color.z])
+                .map(scale_color)
+                .map(|c| c.to_string())
+                .collect();
+            ppm.push_str(&wrap_line(&components));
+            ppm.push('\n');
+        }
+
+        ppm
+    }
+}
+
+fn scale_color(c: f64) -> u8 {
+    (c.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+fn wrap_line(components: &[String]) -> String {
+    let mut lines = Vec::new();
+    let mut current = String::new();
+
+    for component in components {
+        let extra = if current.is_empty() { 0 } else { 1 };
+        if current.len() + extra + component.len() > MAX_LINE_LENGTH {
+            lines.push(std::mem::take(&mut current));
+        }
+        if !current.is_empty() {
+            current.push(' ');
+        }
+        current.push_str(component);
+    }
+    lines.push(current);
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn creating_a_canvas() {
+        let c = Canvas::new(10, 20);
+
+        assert_eq!(c.width(), 10);
+        assert_eq!(c.height(), 20);
+        for y in 0..20 {
+            for x in 0..10 {
+                assert_eq!(c.pixel_at(x, y), Tuple::new(0.0, 0.0, 0.0, 0.0));
+            }
+        }
+    }
+
+    #[test]
+    fn writing_a_pixel_to_a_canvas() {
+        let mut c = Canvas::new(10, 20);
+        let red = Tuple::new(1.0, 0.0, 0.0, 0.0);
+
+        c.write_pixel(2, 3, red);
+
+        assert_eq!(c.pixel_at(2, 3), red);
+    }
+
+    #[test]
+    fn ppm_header() {
+        let c = Canvas::new(5, 3);
+        let ppm = c.to_ppm();
+        let header: Vec<&str> = ppm.lines().take(3).collect();
+
+        assert_eq!(header, vec!["P3", "5 3", "255"]);
+    }
+
+    #[test]
+    fn ppm_pixel_data() {
+        let mut c = Canvas::new(5, 3);
+        c.write_pixel(0, 0, Tuple::new(1.5, 0.0, 0.0, 0.0));
+        c.write_pixel(2, 1, Tuple::new(0.0, 0.5, 0.0, 0.0));
+        c.write_pixel(4, 2, Tuple::new(-0.5, 0.0, 1.0, 0.0));
+
+        let ppm = c.to_ppm();
+        let lines: Vec<&str> = ppm.lines().skip(3).collect();
+
+        assert_eq!(
+            lines,
+            vec![
+                "255 0 0 0 0 0 0 0 0 0 0 0 0 0 0",
+                "0 0 0 0 0 0 0 128 0 0 0 0 0 0 0",
+                "0 0 0 0 0 0 0 0 0 0 0 0 0 0 255",
+            ]
+        );
+    }
+
+    #[test]
+    fn splitting_long_lines_in_ppm_files() {
+        let mut c = Canvas::new(10, 2);
+        let color = Tuple::new(1.0, 0.8, 0.6, 0.0);
+        for y in 0..2 {
+            for x in 0..10 {
+                c.write_pixel(x, y, color);
+            }
+        }
+
+        let ppm = c.to_ppm();
+        let lines: Vec<&str> = ppm.lines().skip(3).collect();
+
+        assert_eq!(
+            lines,
+            vec![
+                "255 204 153 255 204 153 255 204 153 255 204 153 255 204 153 255 204",
+                "153 255 204 153 255 204 153 255 204 153 255 204 153",
+                "255 204 153 255 204 153 255 204 153 255 204 153 255 204 153 255 204",
+                "153 255 204 153 255 204 153 255 204 153 255 204 153",
+            ]
+        );
+
+        for line in &lines {
+            assert!(line.len() < MAX_LINE_LENGTH);
+        }
+    }
+
+    #[test]
+    fn ppm_files_are_terminated_by_a_newline() {
+        let c = Canvas::new(5, 3);
+
+        assert!(c.to_ppm().ends_with('\n'));
+    }
+}
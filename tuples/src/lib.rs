@@ -1,14 +1,22 @@
 use std::ops::{Add, Div, Mul, Neg, Sub};
 
-const EPS: f64 = 1e-5;
-
-/// A structure for tuples, vectors has w = 0 and points w = 1
-#[derive(Debug)]
-struct Tuple {
-    x: f64,
-    y: f64,
-    z: f64,
-    w: f64,
+pub mod canvas;
+pub mod light;
+pub mod matrix;
+pub mod ray;
+
+pub(crate) const EPS: f64 = 1e-5;
+
+/// A structure for tuples, vectors has w = 0 and points w = 1.
+///
+/// Also reused as the backing store for colors (`x = r`, `y = g`, `z = b`)
+/// wherever a module needs a small arithmetic triple, e.g. `Canvas`.
+#[derive(Debug, Clone, Copy)]
+pub struct Tuple {
+    pub(crate) x: f64,
+    pub(crate) y: f64,
+    pub(crate) z: f64,
+    pub(crate) w: f64,
 }
 
 impl PartialEq for Tuple {
@@ -112,6 +120,174 @@ impl Tuple {
     pub fn is_vector(&self) -> bool {
         self.w == 0.0
     }
+
+    pub fn dot(&self, other: &Tuple) -> f64 {
+        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+    }
+
+    pub fn cross(&self, other: &Tuple) -> Tuple {
+        Tuple::new_vector(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+
+    pub fn magnitude(&self) -> f64 {
+        (self.x * self.x + self.y * self.y + self.z * self.z + self.w * self.w).sqrt()
+    }
+
+    pub fn normalize(&self) -> Tuple {
+        let magnitude = self.magnitude();
+        Tuple::new(
+            self.x / magnitude,
+            self.y / magnitude,
+            self.z / magnitude,
+            self.w / magnitude,
+        )
+    }
+
+    /// Reflects `self` about `normal`.
+    pub fn reflect(&self, normal: &Tuple) -> Tuple {
+        *self - *normal * (2.0 * self.dot(normal))
+    }
+
+    /// Componentwise product, used to blend colors.
+    pub(crate) fn hadamard(&self, other: &Tuple) -> Tuple {
+        Tuple::new(
+            self.x * other.x,
+            self.y * other.y,
+            self.z * other.z,
+            self.w * other.w,
+        )
+    }
+}
+
+/// A point in space, i.e. a `Tuple` with `w = 1`.
+///
+/// Unlike the raw `Tuple`, the algebra here is enforced at compile time:
+/// points can't be added together or negated, only translated by a `Vector`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Point(Tuple);
+
+/// A direction/displacement in space, i.e. a `Tuple` with `w = 0`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Vector(Tuple);
+
+impl Point {
+    pub fn new(x: f64, y: f64, z: f64) -> Self {
+        Point(Tuple::new_point(x, y, z))
+    }
+
+    pub fn x(&self) -> f64 {
+        self.0.x
+    }
+
+    pub fn y(&self) -> f64 {
+        self.0.y
+    }
+
+    pub fn z(&self) -> f64 {
+        self.0.z
+    }
+}
+
+impl Vector {
+    pub fn new(x: f64, y: f64, z: f64) -> Self {
+        Vector(Tuple::new_vector(x, y, z))
+    }
+
+    pub fn x(&self) -> f64 {
+        self.0.x
+    }
+
+    pub fn y(&self) -> f64 {
+        self.0.y
+    }
+
+    pub fn z(&self) -> f64 {
+        self.0.z
+    }
+
+    pub fn normalize(&self) -> Vector {
+        Vector(self.0.normalize())
+    }
+}
+
+impl From<Tuple> for Point {
+    fn from(t: Tuple) -> Self {
+        assert!(
+            (t.w - 1.0).abs() < EPS,
+            "cannot build a Point from a Tuple with w = {}",
+            t.w
+        );
+        Point(t)
+    }
+}
+
+impl From<Tuple> for Vector {
+    fn from(t: Tuple) -> Self {
+        assert!(
+            t.w.abs() < EPS,
+            "cannot build a Vector from a Tuple with w = {}",
+            t.w
+        );
+        Vector(t)
+    }
+}
+
+impl From<Point> for Tuple {
+    fn from(p: Point) -> Self {
+        p.0
+    }
+}
+
+impl From<Vector> for Tuple {
+    fn from(v: Vector) -> Self {
+        v.0
+    }
+}
+
+impl Sub for Point {
+    type Output = Vector;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Vector(self.0 - rhs.0)
+    }
+}
+
+impl Add<Vector> for Point {
+    type Output = Point;
+    fn add(self, rhs: Vector) -> Self::Output {
+        Point(self.0 + rhs.0)
+    }
+}
+
+impl Add for Vector {
+    type Output = Vector;
+    fn add(self, rhs: Self) -> Self::Output {
+        Vector(self.0 + rhs.0)
+    }
+}
+
+impl Neg for Vector {
+    type Output = Vector;
+    fn neg(self) -> Self::Output {
+        Vector(-self.0)
+    }
+}
+
+impl Mul<f64> for Vector {
+    type Output = Vector;
+    fn mul(self, rhs: f64) -> Self::Output {
+        Vector(self.0 * rhs)
+    }
+}
+
+impl Div<f64> for Vector {
+    type Output = Vector;
+    fn div(self, rhs: f64) -> Self::Output {
+        Vector(self.0 / rhs)
+    }
 }
 
 #[cfg(test)]
@@ -221,4 +397,143 @@ mod tests {
 
         assert_eq!(a / 2.0, a_mult);
     }
+
+    #[test]
+    fn point_and_vector_accessors() {
+        let p = Point::new(4.0, -4.0, 3.0);
+        let v = Vector::new(4.0, -4.0, 3.0);
+
+        assert_eq!((p.x(), p.y(), p.z()), (4.0, -4.0, 3.0));
+        assert_eq!((v.x(), v.y(), v.z()), (4.0, -4.0, 3.0));
+    }
+
+    #[test]
+    fn point_from_tuple_requires_w_1() {
+        let t = Tuple::new_point(1.0, 2.0, 3.0);
+        let p: Point = t.into();
+
+        assert_eq!(p, Point::new(1.0, 2.0, 3.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn point_from_tuple_panics_on_wrong_w() {
+        let t = Tuple::new_vector(1.0, 2.0, 3.0);
+        let _: Point = t.into();
+    }
+
+    #[test]
+    fn vector_from_tuple_requires_w_0() {
+        let t = Tuple::new_vector(1.0, 2.0, 3.0);
+        let v: Vector = t.into();
+
+        assert_eq!(v, Vector::new(1.0, 2.0, 3.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn vector_from_tuple_panics_on_wrong_w() {
+        let t = Tuple::new_point(1.0, 2.0, 3.0);
+        let _: Vector = t.into();
+    }
+
+    #[test]
+    fn subtracting_two_points_gives_a_vector() {
+        let p1 = Point::new(3.0, 2.0, 1.0);
+        let p2 = Point::new(5.0, 6.0, 7.0);
+
+        assert_eq!(p1 - p2, Vector::new(-2.0, -4.0, -6.0));
+    }
+
+    #[test]
+    fn adding_a_vector_to_a_point_gives_a_point() {
+        let p = Point::new(3.0, 2.0, 1.0);
+        let v = Vector::new(-2.0, -4.0, -6.0);
+
+        assert_eq!(p + v, Point::new(1.0, -2.0, -5.0));
+    }
+
+    #[test]
+    fn adding_two_vectors_gives_a_vector() {
+        let v1 = Vector::new(3.0, 2.0, 1.0);
+        let v2 = Vector::new(-2.0, -4.0, -6.0);
+
+        assert_eq!(v1 + v2, Vector::new(1.0, -2.0, -5.0));
+    }
+
+    #[test]
+    fn negating_a_vector() {
+        let v = Vector::new(1.0, -2.0, 3.0);
+
+        assert_eq!(-v, Vector::new(-1.0, 2.0, -3.0));
+    }
+
+    #[test]
+    fn multiplying_and_dividing_a_vector_by_a_scalar() {
+        let v = Vector::new(1.0, -2.0, 3.0);
+
+        assert_eq!(v * 2.0, Vector::new(2.0, -4.0, 6.0));
+        assert_eq!(v / 2.0, Vector::new(0.5, -1.0, 1.5));
+    }
+
+    #[test]
+    fn magnitude_of_unit_vectors() {
+        assert_eq!(Tuple::new_vector(1.0, 0.0, 0.0).magnitude(), 1.0);
+        assert_eq!(Tuple::new_vector(0.0, 1.0, 0.0).magnitude(), 1.0);
+        assert_eq!(Tuple::new_vector(0.0, 0.0, 1.0).magnitude(), 1.0);
+    }
+
+    #[test]
+    fn magnitude_of_an_arbitrary_vector() {
+        let v = Tuple::new_vector(1.0, 2.0, 3.0);
+
+        assert!((v.magnitude() - 14.0_f64.sqrt()).abs() < EPS);
+    }
+
+    #[test]
+    fn normalizing_a_vector_gives_a_magnitude_of_one() {
+        let v = Tuple::new_vector(1.0, 2.0, 3.0);
+
+        assert!((v.normalize().magnitude() - 1.0).abs() < EPS);
+    }
+
+    #[test]
+    fn normalizing_an_axis_aligned_vector() {
+        let v = Tuple::new_vector(4.0, 0.0, 0.0);
+
+        assert_eq!(v.normalize(), Tuple::new_vector(1.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn dot_product_of_two_tuples() {
+        let a = Tuple::new_vector(1.0, 2.0, 3.0);
+        let b = Tuple::new_vector(2.0, 3.0, 4.0);
+
+        assert_eq!(a.dot(&b), 20.0);
+    }
+
+    #[test]
+    fn dot_product_of_orthogonal_unit_vectors_is_zero() {
+        let x = Tuple::new_vector(1.0, 0.0, 0.0);
+        let y = Tuple::new_vector(0.0, 1.0, 0.0);
+
+        assert_eq!(x.dot(&y), 0.0);
+    }
+
+    #[test]
+    fn cross_product_of_two_vectors() {
+        let a = Tuple::new_vector(1.0, 2.0, 3.0);
+        let b = Tuple::new_vector(2.0, 3.0, 4.0);
+
+        assert_eq!(a.cross(&b), Tuple::new_vector(-1.0, 2.0, -1.0));
+        assert_eq!(b.cross(&a), Tuple::new_vector(1.0, -2.0, 1.0));
+    }
+
+    #[test]
+    fn cross_product_of_axis_aligned_unit_vectors() {
+        let x = Tuple::new_vector(1.0, 0.0, 0.0);
+        let y = Tuple::new_vector(0.0, 1.0, 0.0);
+
+        assert_eq!(x.cross(&y), Tuple::new_vector(0.0, 0.0, 1.0));
+    }
 }
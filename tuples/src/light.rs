@@ -0,0 +1,168 @@
+use crate::{Point, Tuple, Vector};
+
+/// A point light source: a single point in space with no size or direction.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PointLight {
+    pub position: Point,
+    pub intensity: Tuple,
+}
+
+impl PointLight {
+    pub fn new(position: Point, intensity: Tuple) -> Self {
+        PointLight {
+            position,
+            intensity,
+        }
+    }
+}
+
+/// The surface properties of an object, used by the Phong reflection model.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Material {
+    pub color: Tuple,
+    pub ambient: f64,
+    pub diffuse: f64,
+    pub specular: f64,
+    pub shininess: f64,
+}
+
+impl Material {
+    pub fn new(color: Tuple, ambient: f64, diffuse: f64, specular: f64, shininess: f64) -> Self {
+        Material {
+            color,
+            ambient,
+            diffuse,
+            specular,
+            shininess,
+        }
+    }
+}
+
+impl Default for Material {
+    fn default() -> Self {
+        Material {
+            color: Tuple::new(1.0, 1.0, 1.0, 0.0),
+            ambient: 0.1,
+            diffuse: 0.9,
+            specular: 0.9,
+            shininess: 200.0,
+        }
+    }
+}
+
+/// Shades `point` using the Phong reflection model.
+pub fn lighting(
+    material: &Material,
+    light: &PointLight,
+    point: Point,
+    eye: Vector,
+    normal: Vector,
+) -> Tuple {
+    let effective_color = material.color.hadamard(&light.intensity);
+    let lightv = (light.position - point).normalize();
+    let ambient = effective_color * material.ambient;
+
+    let light_dot_normal = Tuple::from(lightv).dot(&Tuple::from(normal));
+    if light_dot_normal < 0.0 {
+        return ambient;
+    }
+
+    let diffuse = effective_color * material.diffuse * light_dot_normal;
+
+    let reflectv = Tuple::from(-lightv).reflect(&Tuple::from(normal));
+    let reflect_dot_eye = reflectv.dot(&Tuple::from(eye));
+    if reflect_dot_eye <= 0.0 {
+        return ambient + diffuse;
+    }
+
+    let factor = reflect_dot_eye.powf(material.shininess);
+    let specular = light.intensity * material.specular * factor;
+
+    ambient + diffuse + specular
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_default_material() {
+        let m = Material::default();
+
+        assert_eq!(m.color, Tuple::new(1.0, 1.0, 1.0, 0.0));
+        assert_eq!(m.ambient, 0.1);
+        assert_eq!(m.diffuse, 0.9);
+        assert_eq!(m.specular, 0.9);
+        assert_eq!(m.shininess, 200.0);
+    }
+
+    #[test]
+    fn lighting_with_the_eye_between_the_light_and_the_surface() {
+        let m = Material::default();
+        let position = Point::new(0.0, 0.0, 0.0);
+
+        let eye = Vector::new(0.0, 0.0, -1.0);
+        let normal = Vector::new(0.0, 0.0, -1.0);
+        let light = PointLight::new(Point::new(0.0, 0.0, -10.0), Tuple::new(1.0, 1.0, 1.0, 0.0));
+
+        let result = lighting(&m, &light, position, eye, normal);
+
+        assert_eq!(result, Tuple::new(1.9, 1.9, 1.9, 0.0));
+    }
+
+    #[test]
+    fn lighting_with_the_eye_between_light_and_surface_eye_offset_45_degrees() {
+        let m = Material::default();
+        let position = Point::new(0.0, 0.0, 0.0);
+
+        let eye = Vector::new(0.0, 2.0_f64.sqrt() / 2.0, -(2.0_f64.sqrt() / 2.0));
+        let normal = Vector::new(0.0, 0.0, -1.0);
+        let light = PointLight::new(Point::new(0.0, 0.0, -10.0), Tuple::new(1.0, 1.0, 1.0, 0.0));
+
+        let result = lighting(&m, &light, position, eye, normal);
+
+        assert_eq!(result, Tuple::new(1.0, 1.0, 1.0, 0.0));
+    }
+
+    #[test]
+    fn lighting_with_eye_opposite_surface_light_offset_45_degrees() {
+        let m = Material::default();
+        let position = Point::new(0.0, 0.0, 0.0);
+
+        let eye = Vector::new(0.0, 0.0, -1.0);
+        let normal = Vector::new(0.0, 0.0, -1.0);
+        let light = PointLight::new(Point::new(0.0, 10.0, -10.0), Tuple::new(1.0, 1.0, 1.0, 0.0));
+
+        let result = lighting(&m, &light, position, eye, normal);
+
+        assert_eq!(result, Tuple::new(0.7364, 0.7364, 0.7364, 0.0));
+    }
+
+    #[test]
+    fn lighting_with_eye_in_the_path_of_the_reflection_vector() {
+        let m = Material::default();
+        let position = Point::new(0.0, 0.0, 0.0);
+
+        let eye = Vector::new(0.0, -(2.0_f64.sqrt() / 2.0), -(2.0_f64.sqrt() / 2.0));
+        let normal = Vector::new(0.0, 0.0, -1.0);
+        let light = PointLight::new(Point::new(0.0, 10.0, -10.0), Tuple::new(1.0, 1.0, 1.0, 0.0));
+
+        let result = lighting(&m, &light, position, eye, normal);
+
+        assert_eq!(result, Tuple::new(1.6364, 1.6364, 1.6364, 0.0));
+    }
+
+    #[test]
+    fn lighting_with_the_light_behind_the_surface() {
+        let m = Material::default();
+        let position = Point::new(0.0, 0.0, 0.0);
+
+        let eye = Vector::new(0.0, 0.0, -1.0);
+        let normal = Vector::new(0.0, 0.0, -1.0);
+        let light = PointLight::new(Point::new(0.0, 0.0, 10.0), Tuple::new(1.0, 1.0, 1.0, 0.0));
+
+        let result = lighting(&m, &light, position, eye, normal);
+
+        assert_eq!(result, Tuple::new(0.1, 0.1, 0.1, 0.0));
+    }
+}
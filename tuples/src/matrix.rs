@@ -0,0 +1,347 @@
+use std::ops::Mul;
+
+use crate::{Tuple, EPS};
+
+/// A square matrix backed by a flat, row-major buffer.
+#[derive(Debug, Clone)]
+pub struct Matrix {
+    data: Vec<f64>,
+    size: usize,
+}
+
+impl Matrix {
+    pub fn new(size: usize, data: Vec<f64>) -> Self {
+        assert_eq!(data.len(), size * size, "matrix data does not match size");
+        Matrix { data, size }
+    }
+
+    pub fn identity(size: usize) -> Self {
+        let mut data = vec![0.0; size * size];
+        for i in 0..size {
+            data[i * size + i] = 1.0;
+        }
+        Matrix { data, size }
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> f64 {
+        self.data[row * self.size + col]
+    }
+
+    fn set(&mut self, row: usize, col: usize, value: f64) {
+        self.data[row * self.size + col] = value;
+    }
+
+    pub fn transpose(&self) -> Matrix {
+        let mut result = Matrix::identity(self.size);
+        for row in 0..self.size {
+            for col in 0..self.size {
+                result.set(col, row, self.get(row, col));
+            }
+        }
+        result
+    }
+
+    fn submatrix(&self, row: usize, col: usize) -> Matrix {
+        let mut data = Vec::with_capacity((self.size - 1) * (self.size - 1));
+        for r in 0..self.size {
+            if r == row {
+                continue;
+            }
+            for c in 0..self.size {
+                if c == col {
+                    continue;
+                }
+                data.push(self.get(r, c));
+            }
+        }
+        Matrix::new(self.size - 1, data)
+    }
+
+    fn minor(&self, row: usize, col: usize) -> f64 {
+        self.submatrix(row, col).determinant()
+    }
+
+    fn cofactor(&self, row: usize, col: usize) -> f64 {
+        let minor = self.minor(row, col);
+        if (row + col) % 2 == 1 {
+            -minor
+        } else {
+            minor
+        }
+    }
+
+    pub fn determinant(&self) -> f64 {
+        if self.size == 1 {
+            return self.get(0, 0);
+        }
+        if self.size == 2 {
+            return self.get(0, 0) * self.get(1, 1) - self.get(0, 1) * self.get(1, 0);
+        }
+        (0..self.size)
+            .map(|col| self.get(0, col) * self.cofactor(0, col))
+            .sum()
+    }
+
+    pub fn is_invertible(&self) -> bool {
+        self.determinant().abs() > EPS
+    }
+
+    pub fn inverse(&self) -> Matrix {
+        let det = self.determinant();
+        assert!(det.abs() > EPS, "matrix is not invertible");
+
+        let mut result = Matrix::identity(self.size);
+        for row in 0..self.size {
+            for col in 0..self.size {
+                // The inverse is the transpose of the cofactor matrix, scaled by 1/det.
+                result.set(col, row, self.cofactor(row, col) / det);
+            }
+        }
+        result
+    }
+}
+
+impl PartialEq for Matrix {
+    fn eq(&self, other: &Self) -> bool {
+        self.size == other.size
+            && self
+                .data
+                .iter()
+                .zip(other.data.iter())
+                .all(|(a, b)| (a - b).abs() < EPS)
+    }
+}
+
+impl Mul<&Matrix> for &Matrix {
+    type Output = Matrix;
+    fn mul(self, rhs: &Matrix) -> Self::Output {
+        assert_eq!(self.size, rhs.size, "cannot multiply matrices of different sizes");
+
+        let mut result = Matrix::identity(self.size);
+        for row in 0..self.size {
+            for col in 0..self.size {
+                let value = (0..self.size).map(|i| self.get(row, i) * rhs.get(i, col)).sum();
+                result.set(row, col, value);
+            }
+        }
+        result
+    }
+}
+
+impl Mul<Matrix> for Matrix {
+    type Output = Matrix;
+    fn mul(self, rhs: Matrix) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl Mul<Tuple> for &Matrix {
+    type Output = Tuple;
+    fn mul(self, rhs: Tuple) -> Self::Output {
+        assert_eq!(self.size, 4, "can only multiply a 4x4 matrix by a tuple");
+
+        let components = [rhs.x, rhs.y, rhs.z, rhs.w];
+        let mut result = [0.0; 4];
+        for (row, value) in result.iter_mut().enumerate() {
+            *value = (0..4).map(|col| self.get(row, col) * components[col]).sum();
+        }
+        Tuple::new(result[0], result[1], result[2], result[3])
+    }
+}
+
+impl Mul<Tuple> for Matrix {
+    type Output = Tuple;
+    fn mul(self, rhs: Tuple) -> Self::Output {
+        &self * rhs
+    }
+}
+
+pub fn translation(x: f64, y: f64, z: f64) -> Matrix {
+    let mut m = Matrix::identity(4);
+    m.set(0, 3, x);
+    m.set(1, 3, y);
+    m.set(2, 3, z);
+    m
+}
+
+pub fn scaling(x: f64, y: f64, z: f64) -> Matrix {
+    let mut m = Matrix::identity(4);
+    m.set(0, 0, x);
+    m.set(1, 1, y);
+    m.set(2, 2, z);
+    m
+}
+
+pub fn rotation_x(rad: f64) -> Matrix {
+    let mut m = Matrix::identity(4);
+    m.set(1, 1, rad.cos());
+    m.set(1, 2, -rad.sin());
+    m.set(2, 1, rad.sin());
+    m.set(2, 2, rad.cos());
+    m
+}
+
+pub fn rotation_y(rad: f64) -> Matrix {
+    let mut m = Matrix::identity(4);
+    m.set(0, 0, rad.cos());
+    m.set(0, 2, rad.sin());
+    m.set(2, 0, -rad.sin());
+    m.set(2, 2, rad.cos());
+    m
+}
+
+pub fn rotation_z(rad: f64) -> Matrix {
+    let mut m = Matrix::identity(4);
+    m.set(0, 0, rad.cos());
+    m.set(0, 1, -rad.sin());
+    m.set(1, 0, rad.sin());
+    m.set(1, 1, rad.cos());
+    m
+}
+
+pub fn shearing(xy: f64, xz: f64, yx: f64, yz: f64, zx: f64, zy: f64) -> Matrix {
+    let mut m = Matrix::identity(4);
+    m.set(0, 1, xy);
+    m.set(0, 2, xz);
+    m.set(1, 0, yx);
+    m.set(1, 2, yz);
+    m.set(2, 0, zx);
+    m.set(2, 1, zy);
+    m
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn constructing_and_inspecting_a_4x4_matrix() {
+        #[rustfmt::skip]
+        let m = Matrix::new(4, vec![
+            1.0, 2.0, 3.0, 4.0,
+            5.5, 6.5, 7.5, 8.5,
+            9.0, 10.0, 11.0, 12.0,
+            13.5, 14.5, 15.5, 16.5,
+        ]);
+
+        assert_eq!(m.get(0, 0), 1.0);
+        assert_eq!(m.get(1, 2), 7.5);
+        assert_eq!(m.get(3, 3), 16.5);
+    }
+
+    #[test]
+    fn matrix_equality_with_identical_matrices() {
+        let a = Matrix::new(2, vec![1.0, 2.0, 3.0, 4.0]);
+        let b = Matrix::new(2, vec![1.0, 2.0, 3.0, 4.0]);
+
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn multiplying_two_matrices() {
+        #[rustfmt::skip]
+        let a = Matrix::new(4, vec![
+            1.0, 2.0, 3.0, 4.0,
+            5.0, 6.0, 7.0, 8.0,
+            9.0, 8.0, 7.0, 6.0,
+            5.0, 4.0, 3.0, 2.0,
+        ]);
+        #[rustfmt::skip]
+        let b = Matrix::new(4, vec![
+            -2.0, 1.0, 2.0, 3.0,
+            3.0, 2.0, 1.0, -1.0,
+            4.0, 3.0, 6.0, 5.0,
+            1.0, 2.0, 7.0, 8.0,
+        ]);
+        #[rustfmt::skip]
+        let expected = Matrix::new(4, vec![
+            20.0, 22.0, 50.0, 48.0,
+            44.0, 54.0, 114.0, 108.0,
+            40.0, 58.0, 110.0, 102.0,
+            16.0, 26.0, 46.0, 42.0,
+        ]);
+
+        assert_eq!(&a * &b, expected);
+    }
+
+    #[test]
+    fn multiplying_a_matrix_by_the_identity_matrix() {
+        #[rustfmt::skip]
+        let a = Matrix::new(4, vec![
+            0.0, 1.0, 2.0, 4.0,
+            1.0, 2.0, 4.0, 8.0,
+            2.0, 4.0, 8.0, 16.0,
+            4.0, 8.0, 16.0, 32.0,
+        ]);
+
+        assert_eq!(&a * &Matrix::identity(4), a);
+    }
+
+    #[test]
+    fn transposing_a_matrix() {
+        #[rustfmt::skip]
+        let a = Matrix::new(4, vec![
+            0.0, 9.0, 3.0, 0.0,
+            9.0, 8.0, 0.0, 8.0,
+            1.0, 8.0, 5.0, 3.0,
+            0.0, 0.0, 5.0, 8.0,
+        ]);
+        #[rustfmt::skip]
+        let expected = Matrix::new(4, vec![
+            0.0, 9.0, 1.0, 0.0,
+            9.0, 8.0, 8.0, 0.0,
+            3.0, 0.0, 5.0, 5.0,
+            0.0, 8.0, 3.0, 8.0,
+        ]);
+
+        assert_eq!(a.transpose(), expected);
+    }
+
+    #[test]
+    fn inverse_of_a_matrix_times_itself_is_identity() {
+        #[rustfmt::skip]
+        let a = Matrix::new(4, vec![
+            8.0, -5.0, 9.0, 2.0,
+            7.0, 5.0, 6.0, 1.0,
+            -6.0, 0.0, 9.0, 6.0,
+            -3.0, 0.0, -9.0, -4.0,
+        ]);
+
+        assert_eq!(&a.inverse() * &a, Matrix::identity(4));
+    }
+
+    #[test]
+    fn multiplying_a_product_by_its_inverse_gives_the_original_matrix() {
+        #[rustfmt::skip]
+        let a = Matrix::new(4, vec![
+            3.0, -9.0, 7.0, 3.0,
+            3.0, -8.0, 2.0, -9.0,
+            -4.0, 4.0, 4.0, 1.0,
+            -6.0, 5.0, -1.0, 1.0,
+        ]);
+        #[rustfmt::skip]
+        let b = Matrix::new(4, vec![
+            8.0, 2.0, 2.0, 2.0,
+            3.0, -1.0, 7.0, 0.0,
+            7.0, 0.0, 5.0, 4.0,
+            6.0, -2.0, 0.0, 5.0,
+        ]);
+
+        let c = a.clone() * b.clone();
+
+        assert_eq!(c * b.inverse(), a);
+    }
+
+    #[test]
+    fn chained_transforms_apply_in_reverse_order() {
+        let p = Tuple::new_point(1.0, 0.0, 1.0);
+
+        let a = rotation_x(std::f64::consts::PI / 2.0);
+        let b = scaling(5.0, 5.0, 5.0);
+        let c = translation(10.0, 5.0, 7.0);
+
+        let t = c * b * a;
+
+        assert_eq!(t * p, Tuple::new_point(15.0, 0.0, 7.0));
+    }
+}
@@ -0,0 +1,255 @@
+use crate::matrix::Matrix;
+use crate::{Point, Tuple, Vector};
+
+/// A ray cast from `origin` in `direction`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ray {
+    pub origin: Point,
+    pub direction: Vector,
+}
+
+impl Ray {
+    pub fn new(origin: Point, direction: Vector) -> Self {
+        Ray { origin, direction }
+    }
+
+    /// The point reached after travelling `t` units along the ray.
+    pub fn position(&self, t: f64) -> Point {
+        self.origin + self.direction * t
+    }
+
+    /// Applies `transform` to both the origin and the direction.
+    pub fn transform(&self, transform: &Matrix) -> Ray {
+        Ray::new(
+            Point::from(transform * Tuple::from(self.origin)),
+            Vector::from(transform * Tuple::from(self.direction)),
+        )
+    }
+}
+
+/// A unit sphere centered on the origin of its own object space, positioned
+/// in world space via `transform`.
+#[derive(Debug, Clone)]
+pub struct Sphere {
+    transform: Matrix,
+}
+
+impl Sphere {
+    pub fn new() -> Self {
+        Sphere {
+            transform: Matrix::identity(4),
+        }
+    }
+
+    pub fn transform(&self) -> &Matrix {
+        &self.transform
+    }
+
+    pub fn set_transform(&mut self, transform: Matrix) {
+        self.transform = transform;
+    }
+
+    /// The `t` values (if any) at which `ray` intersects this sphere.
+    pub fn intersect(&self, ray: &Ray) -> Vec<f64> {
+        let ray = ray.transform(&self.transform.inverse());
+
+        let sphere_to_ray = Tuple::from(ray.origin - Point::new(0.0, 0.0, 0.0));
+        let direction = Tuple::from(ray.direction);
+
+        let a = direction.dot(&direction);
+        let b = 2.0 * direction.dot(&sphere_to_ray);
+        let c = sphere_to_ray.dot(&sphere_to_ray) - 1.0;
+
+        let discriminant = b * b - 4.0 * a * c;
+        if discriminant < 0.0 {
+            return vec![];
+        }
+
+        let sqrt_discriminant = discriminant.sqrt();
+        vec![
+            (-b - sqrt_discriminant) / (2.0 * a),
+            (-b + sqrt_discriminant) / (2.0 * a),
+        ]
+    }
+
+    /// The surface normal at `world_point`, in world space.
+    pub fn normal_at(&self, world_point: Point) -> Vector {
+        let inverse = self.transform.inverse();
+
+        let object_point = Point::from(&inverse * Tuple::from(world_point));
+        let object_normal = object_point - Point::new(0.0, 0.0, 0.0);
+
+        let mut world_normal = inverse.transpose() * Tuple::from(object_normal);
+        world_normal.w = 0.0;
+
+        Vector::from(world_normal.normalize())
+    }
+}
+
+impl Default for Sphere {
+    fn default() -> Self {
+        Sphere::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix::{rotation_z, scaling, translation};
+    use std::f64::consts::PI;
+
+    #[test]
+    fn creating_and_querying_a_ray() {
+        let origin = Point::new(1.0, 2.0, 3.0);
+        let direction = Vector::new(4.0, 5.0, 6.0);
+
+        let r = Ray::new(origin, direction);
+
+        assert_eq!(r.origin, origin);
+        assert_eq!(r.direction, direction);
+    }
+
+    #[test]
+    fn computing_a_point_from_a_distance() {
+        let r = Ray::new(Point::new(2.0, 3.0, 4.0), Vector::new(1.0, 0.0, 0.0));
+
+        assert_eq!(r.position(0.0), Point::new(2.0, 3.0, 4.0));
+        assert_eq!(r.position(1.0), Point::new(3.0, 3.0, 4.0));
+        assert_eq!(r.position(-1.0), Point::new(1.0, 3.0, 4.0));
+        assert_eq!(r.position(2.5), Point::new(4.5, 3.0, 4.0));
+    }
+
+    #[test]
+    fn translating_a_ray() {
+        let r = Ray::new(Point::new(1.0, 2.0, 3.0), Vector::new(0.0, 1.0, 0.0));
+
+        let r2 = r.transform(&translation(3.0, 4.0, 5.0));
+
+        assert_eq!(r2.origin, Point::new(4.0, 6.0, 8.0));
+        assert_eq!(r2.direction, Vector::new(0.0, 1.0, 0.0));
+    }
+
+    #[test]
+    fn scaling_a_ray() {
+        let r = Ray::new(Point::new(1.0, 2.0, 3.0), Vector::new(0.0, 1.0, 0.0));
+
+        let r2 = r.transform(&scaling(2.0, 3.0, 4.0));
+
+        assert_eq!(r2.origin, Point::new(2.0, 6.0, 12.0));
+        assert_eq!(r2.direction, Vector::new(0.0, 3.0, 0.0));
+    }
+
+    #[test]
+    fn a_ray_intersects_a_sphere_at_two_points() {
+        let r = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+        let s = Sphere::new();
+
+        assert_eq!(s.intersect(&r), vec![4.0, 6.0]);
+    }
+
+    #[test]
+    fn a_ray_intersects_a_sphere_at_a_tangent() {
+        let r = Ray::new(Point::new(0.0, 1.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+        let s = Sphere::new();
+
+        assert_eq!(s.intersect(&r), vec![5.0, 5.0]);
+    }
+
+    #[test]
+    fn a_ray_misses_a_sphere() {
+        let r = Ray::new(Point::new(0.0, 2.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+        let s = Sphere::new();
+
+        assert_eq!(s.intersect(&r), Vec::<f64>::new());
+    }
+
+    #[test]
+    fn a_ray_originates_inside_a_sphere() {
+        let r = Ray::new(Point::new(0.0, 0.0, 0.0), Vector::new(0.0, 0.0, 1.0));
+        let s = Sphere::new();
+
+        assert_eq!(s.intersect(&r), vec![-1.0, 1.0]);
+    }
+
+    #[test]
+    fn a_sphere_is_behind_a_ray() {
+        let r = Ray::new(Point::new(0.0, 0.0, 5.0), Vector::new(0.0, 0.0, 1.0));
+        let s = Sphere::new();
+
+        assert_eq!(s.intersect(&r), vec![-6.0, -4.0]);
+    }
+
+    #[test]
+    fn a_sphere_has_a_default_transform() {
+        let s = Sphere::new();
+
+        assert_eq!(s.transform(), &Matrix::identity(4));
+    }
+
+    #[test]
+    fn intersecting_a_scaled_sphere_with_a_ray() {
+        let r = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+        let mut s = Sphere::new();
+        s.set_transform(scaling(2.0, 2.0, 2.0));
+
+        assert_eq!(s.intersect(&r), vec![3.0, 7.0]);
+    }
+
+    #[test]
+    fn intersecting_a_translated_sphere_with_a_ray() {
+        let r = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+        let mut s = Sphere::new();
+        s.set_transform(translation(5.0, 0.0, 0.0));
+
+        assert_eq!(s.intersect(&r), Vec::<f64>::new());
+    }
+
+    #[test]
+    fn normal_on_a_sphere_at_a_point_on_each_axis() {
+        let s = Sphere::new();
+
+        assert_eq!(
+            s.normal_at(Point::new(1.0, 0.0, 0.0)),
+            Vector::new(1.0, 0.0, 0.0)
+        );
+        assert_eq!(
+            s.normal_at(Point::new(0.0, 1.0, 0.0)),
+            Vector::new(0.0, 1.0, 0.0)
+        );
+        assert_eq!(
+            s.normal_at(Point::new(0.0, 0.0, 1.0)),
+            Vector::new(0.0, 0.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn the_normal_is_a_normalized_vector() {
+        let s = Sphere::new();
+        let sqrt3_over_3 = 3.0_f64.sqrt() / 3.0;
+
+        let n = s.normal_at(Point::new(sqrt3_over_3, sqrt3_over_3, sqrt3_over_3));
+
+        assert_eq!(n, n.normalize());
+    }
+
+    #[test]
+    #[allow(clippy::approx_constant)]
+    fn normal_on_a_translated_sphere() {
+        let mut s = Sphere::new();
+        s.set_transform(translation(0.0, 1.0, 0.0));
+
+        let n = s.normal_at(Point::new(0.0, 1.70711, -0.70711));
+
+        assert_eq!(n, Vector::new(0.0, 0.70711, -0.70711));
+    }
+
+    #[test]
+    fn normal_on_a_transformed_sphere() {
+        let mut s = Sphere::new();
+        s.set_transform(scaling(1.0, 0.5, 1.0) * rotation_z(PI / 5.0));
+
+        let n = s.normal_at(Point::new(0.0, 2.0_f64.sqrt() / 2.0, -(2.0_f64.sqrt() / 2.0)));
+
+        assert_eq!(n, Vector::new(0.0, 0.97014, -0.24254));
+    }
+}